@@ -1,7 +1,10 @@
 use std::path::PathBuf;
 
 use anyhow::{ensure, Result};
-use filecoin_proofs_v1::types::{PoRepConfig, PoRepProofPartitions, PoStConfig, SectorSize};
+use filecoin_proofs_v1::types::{
+    AggregateVersion as Groth16AggregateVersion, ApiVersion, PoRepConfig, PoRepProofPartitions,
+    PoStConfig, PoStType, SealCommitPhase2Output, SectorSize,
+};
 use serde::{Deserialize, Serialize};
 
 /// Available seal proofs.
@@ -12,11 +15,25 @@ pub enum RegisteredSealProof {
     StackedDrg256MiBV1,
     StackedDrg1GiBV1,
     StackedDrg32GiBV1,
+    StackedDrg1KiBV1_1,
+    StackedDrg16MiBV1_1,
+    StackedDrg256MiBV1_1,
+    StackedDrg1GiBV1_1,
+    StackedDrg32GiBV1_1,
+    StackedDrg2KiBV1,
+    StackedDrg4KiBV1,
+    StackedDrg8MiBV1,
+    StackedDrg16KiBV1,
+    StackedDrg32KiBV1,
+    StackedDrg32MiBV1,
+    StackedDrg512MiBV1,
+    StackedDrg64GiBV1,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Version {
     V1,
+    V1_1,
 }
 
 impl RegisteredSealProof {
@@ -27,6 +44,27 @@ impl RegisteredSealProof {
         match self {
             StackedDrg1KiBV1 | StackedDrg16MiBV1 | StackedDrg256MiBV1 | StackedDrg1GiBV1
             | StackedDrg32GiBV1 => Version::V1,
+            StackedDrg1KiBV1_1 | StackedDrg16MiBV1_1 | StackedDrg256MiBV1_1
+            | StackedDrg1GiBV1_1 | StackedDrg32GiBV1_1 => Version::V1_1,
+            StackedDrg2KiBV1 | StackedDrg4KiBV1 | StackedDrg8MiBV1 | StackedDrg16KiBV1
+            | StackedDrg32KiBV1 | StackedDrg32MiBV1 | StackedDrg512MiBV1 | StackedDrg64GiBV1 => {
+                Version::V1
+            }
+        }
+    }
+
+    /// Return the API version for this proof.
+    ///
+    /// Distinct from [`Version`]: `Version` identifies the circuit parameter
+    /// set, while `ApiVersion` identifies the calling convention (e.g.
+    /// `porep_id` vs. seed handling) expected by callers. The API version is
+    /// derived from `version()` alone (`V1` gives `V1_0_0`, `V1_1` gives
+    /// `V1_1_0`), so variants that share a `Version` also share an
+    /// `ApiVersion`.
+    pub fn api_version(self) -> ApiVersion {
+        match self.version() {
+            Version::V1 => ApiVersion::V1_0_0,
+            Version::V1_1 => ApiVersion::V1_1_0,
         }
     }
 
@@ -35,11 +73,19 @@ impl RegisteredSealProof {
         use filecoin_proofs_v1::constants;
         use RegisteredSealProof::*;
         let size = match self {
-            StackedDrg1KiBV1 => constants::SECTOR_SIZE_ONE_KIB,
-            StackedDrg16MiBV1 => constants::SECTOR_SIZE_16_MIB,
-            StackedDrg256MiBV1 => constants::SECTOR_SIZE_256_MIB,
-            StackedDrg1GiBV1 => constants::SECTOR_SIZE_1_GIB,
-            StackedDrg32GiBV1 => constants::SECTOR_SIZE_32_GIB,
+            StackedDrg1KiBV1 | StackedDrg1KiBV1_1 => constants::SECTOR_SIZE_ONE_KIB,
+            StackedDrg16MiBV1 | StackedDrg16MiBV1_1 => constants::SECTOR_SIZE_16_MIB,
+            StackedDrg256MiBV1 | StackedDrg256MiBV1_1 => constants::SECTOR_SIZE_256_MIB,
+            StackedDrg1GiBV1 | StackedDrg1GiBV1_1 => constants::SECTOR_SIZE_1_GIB,
+            StackedDrg32GiBV1 | StackedDrg32GiBV1_1 => constants::SECTOR_SIZE_32_GIB,
+            StackedDrg2KiBV1 => constants::SECTOR_SIZE_2_KIB,
+            StackedDrg4KiBV1 => constants::SECTOR_SIZE_4_KIB,
+            StackedDrg8MiBV1 => constants::SECTOR_SIZE_8_MIB,
+            StackedDrg16KiBV1 => constants::SECTOR_SIZE_16_KIB,
+            StackedDrg32KiBV1 => constants::SECTOR_SIZE_32_KIB,
+            StackedDrg32MiBV1 => constants::SECTOR_SIZE_32_MIB,
+            StackedDrg512MiBV1 => constants::SECTOR_SIZE_512_MIB,
+            StackedDrg64GiBV1 => constants::SECTOR_SIZE_64_GIB,
         };
         SectorSize(size)
     }
@@ -47,113 +93,134 @@ impl RegisteredSealProof {
     /// Return the number of partitions for this proof.
     pub fn partitions(self) -> u8 {
         use filecoin_proofs_v1::constants;
-        use RegisteredSealProof::*;
-        match self {
-            StackedDrg1KiBV1 => *constants::POREP_PARTITIONS
-                .read()
-                .unwrap()
-                .get(&constants::SECTOR_SIZE_ONE_KIB)
-                .expect("invalid sector size"),
-            StackedDrg16MiBV1 => *constants::POREP_PARTITIONS
-                .read()
-                .unwrap()
-                .get(&constants::SECTOR_SIZE_16_MIB)
-                .expect("invalid sector size"),
-            StackedDrg256MiBV1 => *constants::POREP_PARTITIONS
-                .read()
-                .unwrap()
-                .get(&constants::SECTOR_SIZE_256_MIB)
-                .expect("invalid sector size"),
-            StackedDrg1GiBV1 => *constants::POREP_PARTITIONS
-                .read()
-                .unwrap()
-                .get(&constants::SECTOR_SIZE_1_GIB)
-                .expect("invalid sector size"),
-            StackedDrg32GiBV1 => *constants::POREP_PARTITIONS
-                .read()
-                .unwrap()
-                .get(&constants::SECTOR_SIZE_32_GIB)
-                .expect("invalid sector size"),
-        }
+
+        *constants::POREP_PARTITIONS
+            .read()
+            .unwrap()
+            .get(&u64::from(self.sector_size()))
+            .expect("invalid sector size")
     }
 
     pub fn single_partition_proof_len(self) -> usize {
+        filecoin_proofs_v1::SINGLE_PARTITION_PROOF_LEN
+    }
+
+    /// Return a stable numeric identifier for this proof, used as the low
+    /// bytes of its `porep_id`.
+    ///
+    /// Assigned in declaration order; existing values must never change or be
+    /// reused once released, since they are encoded into `porep_id` and thus
+    /// into already-sealed sectors. This numbering is local to this registry
+    /// and is not guaranteed to match the Filecoin network's registered-proof
+    /// IDs; a registry that must interoperate with real sealed sectors needs
+    /// its nonces reconciled with the canonical assignment before use.
+    fn nonce(self) -> u64 {
         use RegisteredSealProof::*;
 
         match self {
-            StackedDrg1KiBV1 | StackedDrg16MiBV1 | StackedDrg256MiBV1 | StackedDrg1GiBV1
-            | StackedDrg32GiBV1 => filecoin_proofs_v1::SINGLE_PARTITION_PROOF_LEN,
+            StackedDrg1KiBV1 => 0,
+            StackedDrg16MiBV1 => 1,
+            StackedDrg256MiBV1 => 2,
+            StackedDrg1GiBV1 => 3,
+            StackedDrg32GiBV1 => 4,
+            StackedDrg1KiBV1_1 => 5,
+            StackedDrg16MiBV1_1 => 6,
+            StackedDrg256MiBV1_1 => 7,
+            StackedDrg1GiBV1_1 => 8,
+            StackedDrg32GiBV1_1 => 9,
+            StackedDrg2KiBV1 => 10,
+            StackedDrg4KiBV1 => 11,
+            StackedDrg8MiBV1 => 12,
+            StackedDrg16KiBV1 => 13,
+            StackedDrg32KiBV1 => 14,
+            StackedDrg32MiBV1 => 15,
+            StackedDrg512MiBV1 => 16,
+            StackedDrg64GiBV1 => 17,
         }
     }
 
-    pub fn as_v1_config(self) -> PoRepConfig {
-        use RegisteredSealProof::*;
-
-        assert_eq!(self.version(), Version::V1);
+    /// Return the deterministic 32-byte `porep_id` for this proof.
+    ///
+    /// Replaces the random graph `seed` used by earlier replications with a
+    /// value derived only from the registered proof type, so that the
+    /// replication graph is reproducible from the proof identifier alone.
+    /// The proof's `nonce` is packed little-endian into the low bytes, with
+    /// the remainder zeroed.
+    pub fn porep_id(self) -> [u8; 32] {
+        let mut porep_id = [0u8; 32];
+        let nonce = self.nonce().to_le_bytes();
+        porep_id[..nonce.len()].copy_from_slice(&nonce);
+        porep_id
+    }
 
-        match self {
-            StackedDrg1KiBV1 | StackedDrg16MiBV1 | StackedDrg256MiBV1 | StackedDrg1GiBV1
-            | StackedDrg32GiBV1 => PoRepConfig {
-                sector_size: self.sector_size(),
-                partitions: PoRepProofPartitions(self.partitions()),
-            },
-            // _ => panic!("Can only be called on V1 configs"),
+    pub fn as_v1_config(self) -> PoRepConfig {
+        PoRepConfig {
+            sector_size: self.sector_size(),
+            partitions: PoRepProofPartitions(self.partitions()),
+            porep_id: self.porep_id(),
+            api_version: self.api_version(),
         }
     }
 
     /// Returns the circuit identifier.
     pub fn circuit_identifier(self) -> Result<String> {
-        match self.version() {
-            Version::V1 => self.as_v1_config().get_cache_identifier(),
-        }
+        self.as_v1_config().get_cache_identifier()
     }
 
     pub fn cache_verifying_key_path(self) -> Result<PathBuf> {
-        match self.version() {
-            Version::V1 => self.as_v1_config().get_cache_verifying_key_path(),
-        }
+        self.as_v1_config().get_cache_verifying_key_path()
     }
 
     pub fn cache_params_path(self) -> Result<PathBuf> {
-        match self.version() {
-            Version::V1 => self.as_v1_config().get_cache_params_path(),
-        }
+        self.as_v1_config().get_cache_params_path()
     }
 
     pub fn verifying_key_cid(self) -> Result<String> {
-        match self.version() {
-            Version::V1 => {
-                let id = self.as_v1_config().get_cache_identifier()?;
-                let params = filecoin_proofs_v1::constants::PARAMETERS.get(&format!("{}.vk", &id));
-                ensure!(params.is_some(), "missing params for {}", &id);
+        let id = self.as_v1_config().get_cache_identifier()?;
+        let params = filecoin_proofs_v1::constants::PARAMETERS.get(&format!("{}.vk", &id));
+        ensure!(params.is_some(), "missing params for {}", &id);
 
-                Ok(params.unwrap().cid.clone())
-            }
-        }
+        Ok(params.unwrap().cid.clone())
     }
 
     pub fn params_cid(self) -> Result<String> {
-        match self.version() {
-            Version::V1 => {
-                let id = self.as_v1_config().get_cache_identifier()?;
-                let params =
-                    filecoin_proofs_v1::constants::PARAMETERS.get(&format!("{}.params", &id));
-                ensure!(params.is_some(), "missing params for {}", &id);
+        let id = self.as_v1_config().get_cache_identifier()?;
+        let params = filecoin_proofs_v1::constants::PARAMETERS.get(&format!("{}.params", &id));
+        ensure!(params.is_some(), "missing params for {}", &id);
 
-                Ok(params.unwrap().cid.clone())
-            }
-        }
+        Ok(params.unwrap().cid.clone())
     }
 }
 
-/// Available seal proofs.
+/// Available PoSt proofs.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum RegisteredPoStProof {
-    StackedDrg1KiBV1,
-    StackedDrg16MiBV1,
-    StackedDrg256MiBV1,
-    StackedDrg1GiBV1,
-    StackedDrg32GiBV1,
+    StackedDrgWinning1KiBV1,
+    StackedDrgWinning16MiBV1,
+    StackedDrgWinning256MiBV1,
+    StackedDrgWinning1GiBV1,
+    StackedDrgWinning32GiBV1,
+    StackedDrgWindow1KiBV1,
+    StackedDrgWindow16MiBV1,
+    StackedDrgWindow256MiBV1,
+    StackedDrgWindow1GiBV1,
+    StackedDrgWindow32GiBV1,
+    StackedDrgWinning2KiBV1,
+    StackedDrgWinning4KiBV1,
+    StackedDrgWinning8MiBV1,
+    StackedDrgWinning16KiBV1,
+    StackedDrgWinning32KiBV1,
+    StackedDrgWinning32MiBV1,
+    StackedDrgWinning512MiBV1,
+    StackedDrgWinning64GiBV1,
+    StackedDrgWindow2KiBV1,
+    StackedDrgWindow4KiBV1,
+    StackedDrgWindow8MiBV1,
+    StackedDrgWindow16KiBV1,
+    StackedDrgWindow32KiBV1,
+    StackedDrgWindow32MiBV1,
+    StackedDrgWindow512MiBV1,
+    StackedDrgWindow64GiBV1,
 }
 
 impl RegisteredPoStProof {
@@ -162,8 +229,81 @@ impl RegisteredPoStProof {
         use RegisteredPoStProof::*;
 
         match self {
-            StackedDrg1KiBV1 | StackedDrg16MiBV1 | StackedDrg256MiBV1 | StackedDrg1GiBV1
-            | StackedDrg32GiBV1 => Version::V1,
+            StackedDrgWinning1KiBV1
+            | StackedDrgWinning16MiBV1
+            | StackedDrgWinning256MiBV1
+            | StackedDrgWinning1GiBV1
+            | StackedDrgWinning32GiBV1
+            | StackedDrgWindow1KiBV1
+            | StackedDrgWindow16MiBV1
+            | StackedDrgWindow256MiBV1
+            | StackedDrgWindow1GiBV1
+            | StackedDrgWindow32GiBV1
+            | StackedDrgWinning2KiBV1
+            | StackedDrgWinning4KiBV1
+            | StackedDrgWinning8MiBV1
+            | StackedDrgWinning16KiBV1
+            | StackedDrgWinning32KiBV1
+            | StackedDrgWinning32MiBV1
+            | StackedDrgWinning512MiBV1
+            | StackedDrgWinning64GiBV1
+            | StackedDrgWindow2KiBV1
+            | StackedDrgWindow4KiBV1
+            | StackedDrgWindow8MiBV1
+            | StackedDrgWindow16KiBV1
+            | StackedDrgWindow32KiBV1
+            | StackedDrgWindow32MiBV1
+            | StackedDrgWindow512MiBV1
+            | StackedDrgWindow64GiBV1 => Version::V1,
+        }
+    }
+
+    /// Return the API version for this proof. See
+    /// [`RegisteredSealProof::api_version`] for why this is distinct from
+    /// [`Version`].
+    pub fn api_version(self) -> ApiVersion {
+        match self.version() {
+            Version::V1 => ApiVersion::V1_0_0,
+            Version::V1_1 => ApiVersion::V1_1_0,
+        }
+    }
+
+    /// Return which of the two PoSt modes this proof is for.
+    ///
+    /// Winning PoSt is run by a single elected sector holder over one sector
+    /// with a high challenge count, as part of leader election. Window PoSt
+    /// is run periodically over every sector a miner holds, batched many
+    /// sectors to a partition with fewer challenges per sector.
+    pub fn typ(self) -> PoStType {
+        use RegisteredPoStProof::*;
+
+        match self {
+            StackedDrgWinning1KiBV1
+            | StackedDrgWinning16MiBV1
+            | StackedDrgWinning256MiBV1
+            | StackedDrgWinning1GiBV1
+            | StackedDrgWinning32GiBV1
+            | StackedDrgWinning2KiBV1
+            | StackedDrgWinning4KiBV1
+            | StackedDrgWinning8MiBV1
+            | StackedDrgWinning16KiBV1
+            | StackedDrgWinning32KiBV1
+            | StackedDrgWinning32MiBV1
+            | StackedDrgWinning512MiBV1
+            | StackedDrgWinning64GiBV1 => PoStType::Winning,
+            StackedDrgWindow1KiBV1
+            | StackedDrgWindow16MiBV1
+            | StackedDrgWindow256MiBV1
+            | StackedDrgWindow1GiBV1
+            | StackedDrgWindow32GiBV1
+            | StackedDrgWindow2KiBV1
+            | StackedDrgWindow4KiBV1
+            | StackedDrgWindow8MiBV1
+            | StackedDrgWindow16KiBV1
+            | StackedDrgWindow32KiBV1
+            | StackedDrgWindow32MiBV1
+            | StackedDrgWindow512MiBV1
+            | StackedDrgWindow64GiBV1 => PoStType::Window,
         }
     }
 
@@ -173,102 +313,318 @@ impl RegisteredPoStProof {
         use RegisteredPoStProof::*;
 
         let size = match self {
-            StackedDrg1KiBV1 => constants::SECTOR_SIZE_ONE_KIB,
-            StackedDrg16MiBV1 => constants::SECTOR_SIZE_16_MIB,
-            StackedDrg256MiBV1 => constants::SECTOR_SIZE_256_MIB,
-            StackedDrg1GiBV1 => constants::SECTOR_SIZE_1_GIB,
-            StackedDrg32GiBV1 => constants::SECTOR_SIZE_32_GIB,
+            StackedDrgWinning1KiBV1 | StackedDrgWindow1KiBV1 => constants::SECTOR_SIZE_ONE_KIB,
+            StackedDrgWinning16MiBV1 | StackedDrgWindow16MiBV1 => constants::SECTOR_SIZE_16_MIB,
+            StackedDrgWinning256MiBV1 | StackedDrgWindow256MiBV1 => constants::SECTOR_SIZE_256_MIB,
+            StackedDrgWinning1GiBV1 | StackedDrgWindow1GiBV1 => constants::SECTOR_SIZE_1_GIB,
+            StackedDrgWinning32GiBV1 | StackedDrgWindow32GiBV1 => constants::SECTOR_SIZE_32_GIB,
+            StackedDrgWinning2KiBV1 | StackedDrgWindow2KiBV1 => constants::SECTOR_SIZE_2_KIB,
+            StackedDrgWinning4KiBV1 | StackedDrgWindow4KiBV1 => constants::SECTOR_SIZE_4_KIB,
+            StackedDrgWinning8MiBV1 | StackedDrgWindow8MiBV1 => constants::SECTOR_SIZE_8_MIB,
+            StackedDrgWinning16KiBV1 | StackedDrgWindow16KiBV1 => constants::SECTOR_SIZE_16_KIB,
+            StackedDrgWinning32KiBV1 | StackedDrgWindow32KiBV1 => constants::SECTOR_SIZE_32_KIB,
+            StackedDrgWinning32MiBV1 | StackedDrgWindow32MiBV1 => constants::SECTOR_SIZE_32_MIB,
+            StackedDrgWinning512MiBV1 | StackedDrgWindow512MiBV1 => constants::SECTOR_SIZE_512_MIB,
+            StackedDrgWinning64GiBV1 | StackedDrgWindow64GiBV1 => constants::SECTOR_SIZE_64_GIB,
         };
         SectorSize(size)
     }
 
+    /// Return the number of sectors proved together in a single partition.
+    ///
+    /// Winning PoSt always proves a single, elected sector. Window PoSt
+    /// batches many sectors into each partition.
+    fn sector_count(self) -> usize {
+        use filecoin_proofs_v1::constants;
+
+        match self.typ() {
+            PoStType::Winning => constants::WINNING_POST_SECTOR_COUNT,
+            PoStType::Window => *constants::WINDOW_POST_SECTOR_COUNT
+                .read()
+                .unwrap()
+                .get(&u64::from(self.sector_size()))
+                .expect("invalid sector size"),
+        }
+    }
+
     /// Return the number of partitions for this proof.
     pub fn partitions(self) -> u8 {
-        use RegisteredPoStProof::*;
+        use filecoin_proofs_v1::constants;
 
-        match self {
-            StackedDrg1KiBV1 | StackedDrg16MiBV1 | StackedDrg256MiBV1 | StackedDrg1GiBV1
-            | StackedDrg32GiBV1 => 1,
+        match self.typ() {
+            PoStType::Winning => 1,
+            PoStType::Window => *constants::WINDOW_POST_PARTITIONS
+                .read()
+                .unwrap()
+                .get(&u64::from(self.sector_size()))
+                .expect("invalid sector size"),
         }
     }
 
     pub fn single_partition_proof_len(self) -> usize {
-        use RegisteredPoStProof::*;
-
-        match self {
-            StackedDrg1KiBV1 | StackedDrg16MiBV1 | StackedDrg256MiBV1 | StackedDrg1GiBV1
-            | StackedDrg32GiBV1 => filecoin_proofs_v1::SINGLE_PARTITION_PROOF_LEN,
-        }
+        filecoin_proofs_v1::SINGLE_PARTITION_PROOF_LEN
     }
 
     pub fn as_v1_config(self) -> PoStConfig {
-        assert_eq!(self.version(), Version::V1);
+        use filecoin_proofs_v1::constants;
 
-        use RegisteredPoStProof::*;
+        let (challenge_count, challenged_nodes) = match self.typ() {
+            PoStType::Winning => (
+                constants::WINNING_POST_CHALLENGE_COUNT,
+                constants::WINNING_POST_CHALLENGED_NODES,
+            ),
+            PoStType::Window => (
+                constants::WINDOW_POST_CHALLENGE_COUNT,
+                constants::WINDOW_POST_CHALLENGED_NODES,
+            ),
+        };
 
-        match self {
-            StackedDrg1KiBV1 | StackedDrg16MiBV1 | StackedDrg256MiBV1 | StackedDrg1GiBV1
-            | StackedDrg32GiBV1 => PoStConfig {
-                sector_size: self.sector_size(),
-                challenge_count: filecoin_proofs_v1::constants::POST_CHALLENGE_COUNT,
-                challenged_nodes: filecoin_proofs_v1::constants::POST_CHALLENGED_NODES,
-                priority: true,
-            },
-            // _ => panic!("Can only be called on V1 configs"),
+        PoStConfig {
+            sector_size: self.sector_size(),
+            challenge_count,
+            sector_count: self.sector_count(),
+            challenged_nodes,
+            typ: self.typ(),
+            priority: true,
+            api_version: self.api_version(),
         }
     }
 
     /// Returns the circuit identifier.
     pub fn circuit_identifier(self) -> Result<String> {
-        match self.version() {
-            Version::V1 => self.as_v1_config().get_cache_identifier(),
-        }
+        self.as_v1_config().get_cache_identifier()
     }
 
     pub fn cache_verifying_key_path(self) -> Result<PathBuf> {
-        match self.version() {
-            Version::V1 => self.as_v1_config().get_cache_verifying_key_path(),
-        }
+        self.as_v1_config().get_cache_verifying_key_path()
     }
 
     pub fn cache_params_path(self) -> Result<PathBuf> {
-        match self.version() {
-            Version::V1 => self.as_v1_config().get_cache_params_path(),
-        }
+        self.as_v1_config().get_cache_params_path()
     }
 
     pub fn verifying_key_cid(self) -> Result<String> {
-        match self.version() {
-            Version::V1 => {
-                let id = self.as_v1_config().get_cache_identifier()?;
-                let params = filecoin_proofs_v1::constants::PARAMETERS.get(&format!("{}.vk", &id));
-                ensure!(params.is_some(), "missing params for {}", &id);
+        let id = self.as_v1_config().get_cache_identifier()?;
+        let params = filecoin_proofs_v1::constants::PARAMETERS.get(&format!("{}.vk", &id));
+        ensure!(params.is_some(), "missing params for {}", &id);
 
-                Ok(params.unwrap().cid.clone())
-            }
-        }
+        Ok(params.unwrap().cid.clone())
     }
 
     pub fn params_cid(self) -> Result<String> {
-        match self.version() {
-            Version::V1 => {
-                let id = self.as_v1_config().get_cache_identifier()?;
-                let params =
-                    filecoin_proofs_v1::constants::PARAMETERS.get(&format!("{}.params", &id));
-                ensure!(params.is_some(), "missing params for {}", &id);
+        let id = self.as_v1_config().get_cache_identifier()?;
+        let params = filecoin_proofs_v1::constants::PARAMETERS.get(&format!("{}.params", &id));
+        ensure!(params.is_some(), "missing params for {}", &id);
 
-                Ok(params.unwrap().cid.clone())
+        Ok(params.unwrap().cid.clone())
+    }
+}
+
+/// Monomorphize `$fn` over the `MerkleTreeTrait` shape matching the sector
+/// size `$size` (in bytes), forwarding `$args` to it.
+///
+/// `filecoin_proofs_v1` is generic over the Merkle-tree shape used to build a
+/// sector (arity, number of sub/top tree layers), so callers need a concrete
+/// shape type to invoke it. This registry only knows sector sizes at
+/// runtime, so `with_shape!` picks the shape for a given size at the call
+/// boundary rather than erasing it, letting the rest of the crate expose
+/// type-correct entry points instead of generic ones.
+#[macro_export]
+macro_rules! with_shape {
+    ($size:expr, $fn:ident $(, $args:expr)*) => {
+        match $size {
+            x if x == u64::from(filecoin_proofs_v1::constants::SECTOR_SIZE_ONE_KIB) => {
+                $fn::<filecoin_proofs_v1::constants::SectorShape1KiB>($($args),*)
+            }
+            x if x == u64::from(filecoin_proofs_v1::constants::SECTOR_SIZE_16_MIB) => {
+                $fn::<filecoin_proofs_v1::constants::SectorShape16MiB>($($args),*)
+            }
+            x if x == u64::from(filecoin_proofs_v1::constants::SECTOR_SIZE_256_MIB) => {
+                $fn::<filecoin_proofs_v1::constants::SectorShape256MiB>($($args),*)
+            }
+            x if x == u64::from(filecoin_proofs_v1::constants::SECTOR_SIZE_1_GIB) => {
+                $fn::<filecoin_proofs_v1::constants::SectorShape1GiB>($($args),*)
+            }
+            x if x == u64::from(filecoin_proofs_v1::constants::SECTOR_SIZE_32_GIB) => {
+                $fn::<filecoin_proofs_v1::constants::SectorShape32GiB>($($args),*)
+            }
+            x if x == u64::from(filecoin_proofs_v1::constants::SECTOR_SIZE_2_KIB) => {
+                $fn::<filecoin_proofs_v1::constants::SectorShape2KiB>($($args),*)
+            }
+            x if x == u64::from(filecoin_proofs_v1::constants::SECTOR_SIZE_4_KIB) => {
+                $fn::<filecoin_proofs_v1::constants::SectorShape4KiB>($($args),*)
             }
+            x if x == u64::from(filecoin_proofs_v1::constants::SECTOR_SIZE_8_MIB) => {
+                $fn::<filecoin_proofs_v1::constants::SectorShape8MiB>($($args),*)
+            }
+            x if x == u64::from(filecoin_proofs_v1::constants::SECTOR_SIZE_16_KIB) => {
+                $fn::<filecoin_proofs_v1::constants::SectorShape16KiB>($($args),*)
+            }
+            x if x == u64::from(filecoin_proofs_v1::constants::SECTOR_SIZE_32_KIB) => {
+                $fn::<filecoin_proofs_v1::constants::SectorShape32KiB>($($args),*)
+            }
+            x if x == u64::from(filecoin_proofs_v1::constants::SECTOR_SIZE_32_MIB) => {
+                $fn::<filecoin_proofs_v1::constants::SectorShape32MiB>($($args),*)
+            }
+            x if x == u64::from(filecoin_proofs_v1::constants::SECTOR_SIZE_512_MIB) => {
+                $fn::<filecoin_proofs_v1::constants::SectorShape512MiB>($($args),*)
+            }
+            x if x == u64::from(filecoin_proofs_v1::constants::SECTOR_SIZE_64_GIB) => {
+                $fn::<filecoin_proofs_v1::constants::SectorShape64GiB>($($args),*)
+            }
+            _ => panic!("unsupported sector size: {}", $size),
+        }
+    };
+}
+
+/// Like [`with_shape!`], but takes a `RegisteredSealProof` or
+/// `RegisteredPoStProof` and reads its sector size instead of an explicit
+/// size.
+#[macro_export]
+macro_rules! self_shape {
+    ($self:expr, $fn:ident $(, $args:expr)*) => {
+        $crate::with_shape!(u64::from($self.sector_size()), $fn $(, $args)*)
+    };
+}
+
+/// Available proof aggregation schemes.
+///
+/// An aggregation proof combines many individual Groth16 seal commit proofs
+/// (one per sector) into a single proof whose size grows only logarithmically
+/// with the number of sectors aggregated, via a TIPP/MIPP inner-pairing-product
+/// argument over structured Pedersen-style commitments to the proofs' group
+/// elements.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RegisteredAggregationProof {
+    SnarkPackV1,
+}
+
+/// Version of the aggregation scheme backing a [`RegisteredAggregationProof`].
+///
+/// Kept distinct from the proof enum itself so that future aggregation
+/// schemes can be introduced as new variants without disturbing the
+/// versioning of existing ones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AggregateVersion {
+    V1,
+}
+
+impl RegisteredAggregationProof {
+    /// Return the aggregation scheme version for this proof.
+    pub fn version(self) -> AggregateVersion {
+        match self {
+            RegisteredAggregationProof::SnarkPackV1 => AggregateVersion::V1,
         }
     }
 }
 
+impl AggregateVersion {
+    /// Map to the upstream `filecoin_proofs_v1` aggregate-version type
+    /// expected by the vanilla aggregation calls.
+    fn as_groth16(self) -> Groth16AggregateVersion {
+        match self {
+            AggregateVersion::V1 => Groth16AggregateVersion::V1,
+        }
+    }
+}
 
+/// Aggregate the individual seal commit (Groth16) proofs for a batch of
+/// sectors sealed under `registered_proof` into a single proof using
+/// `registered_aggregation`.
+///
+/// `comm_rs` and `seeds` must be given in the same sector order as
+/// `commit_outputs`. The number of sectors being aggregated need not be a
+/// power of two; the vanilla aggregation layer pads internally and binds a
+/// domain-separation transcript over all commitment randomness so that proofs
+/// aggregated under different batches cannot be mixed.
+pub fn aggregate_seal_commit_proofs(
+    registered_proof: RegisteredSealProof,
+    registered_aggregation: RegisteredAggregationProof,
+    comm_rs: &[[u8; 32]],
+    seeds: &[[u8; 32]],
+    commit_outputs: &[SealCommitPhase2Output],
+) -> Result<Vec<u8>> {
+    ensure!(!commit_outputs.is_empty(), "cannot aggregate zero proofs");
+    ensure!(
+        comm_rs.len() == seeds.len() && seeds.len() == commit_outputs.len(),
+        "comm_rs, seeds and commit_outputs must all have the same length"
+    );
+
+    match (registered_proof.version(), registered_aggregation.version()) {
+        (Version::V1, AggregateVersion::V1) | (Version::V1_1, AggregateVersion::V1) => self_shape!(
+            registered_proof,
+            call_aggregate_seal_commit_proofs,
+            &registered_proof.as_v1_config(),
+            registered_aggregation.version().as_groth16(),
+            comm_rs,
+            seeds,
+            commit_outputs
+        ),
+    }
+}
+
+fn call_aggregate_seal_commit_proofs<Tree: 'static + filecoin_proofs_v1::MerkleTreeTrait>(
+    porep_config: &PoRepConfig,
+    aggregate_version: Groth16AggregateVersion,
+    comm_rs: &[[u8; 32]],
+    seeds: &[[u8; 32]],
+    commit_outputs: &[SealCommitPhase2Output],
+) -> Result<Vec<u8>> {
+    filecoin_proofs_v1::seal::aggregate_seal_commit_proofs::<Tree>(
+        porep_config,
+        aggregate_version,
+        comm_rs,
+        seeds,
+        commit_outputs,
+    )
+}
+
+/// Verify an aggregate proof produced by [`aggregate_seal_commit_proofs`].
+pub fn verify_aggregate_seal_commit_proofs(
+    registered_proof: RegisteredSealProof,
+    registered_aggregation: RegisteredAggregationProof,
+    aggregate_proof: &[u8],
+    comm_rs: &[[u8; 32]],
+    seeds: &[[u8; 32]],
+) -> Result<bool> {
+    ensure!(
+        !comm_rs.is_empty() && comm_rs.len() == seeds.len(),
+        "comm_rs and seeds must be non-empty and have the same length"
+    );
+
+    match (registered_proof.version(), registered_aggregation.version()) {
+        (Version::V1, AggregateVersion::V1) | (Version::V1_1, AggregateVersion::V1) => self_shape!(
+            registered_proof,
+            call_verify_aggregate_seal_commit_proofs,
+            &registered_proof.as_v1_config(),
+            registered_aggregation.version().as_groth16(),
+            aggregate_proof,
+            comm_rs,
+            seeds
+        ),
+    }
+}
+
+fn call_verify_aggregate_seal_commit_proofs<Tree: 'static + filecoin_proofs_v1::MerkleTreeTrait>(
+    porep_config: &PoRepConfig,
+    aggregate_version: Groth16AggregateVersion,
+    aggregate_proof: &[u8],
+    comm_rs: &[[u8; 32]],
+    seeds: &[[u8; 32]],
+) -> Result<bool> {
+    filecoin_proofs_v1::seal::verify_aggregate_seal_commit_proofs::<Tree>(
+        porep_config,
+        aggregate_version,
+        aggregate_proof,
+        comm_rs,
+        seeds,
+    )
+}
 
 #[cfg(test)]
 pub mod tests {
-    use crate::{RegisteredSealProof, RegisteredPoStProof};
-    use crate::registry::Version;
+    use crate::registry::{AggregateVersion, ApiVersion, Version};
+    use crate::{RegisteredAggregationProof, RegisteredPoStProof, RegisteredSealProof};
     use anyhow::Result;
 
     #[test]
@@ -278,9 +634,23 @@ pub mod tests {
             RegisteredSealProof::StackedDrg16MiBV1,
             RegisteredSealProof::StackedDrg256MiBV1,
             RegisteredSealProof::StackedDrg1GiBV1,
-            RegisteredSealProof::StackedDrg32GiBV1
+            RegisteredSealProof::StackedDrg32GiBV1,
+            RegisteredSealProof::StackedDrg1KiBV1_1,
+            RegisteredSealProof::StackedDrg16MiBV1_1,
+            RegisteredSealProof::StackedDrg256MiBV1_1,
+            RegisteredSealProof::StackedDrg1GiBV1_1,
+            RegisteredSealProof::StackedDrg32GiBV1_1,
+            RegisteredSealProof::StackedDrg2KiBV1,
+            RegisteredSealProof::StackedDrg4KiBV1,
+            RegisteredSealProof::StackedDrg8MiBV1,
+            RegisteredSealProof::StackedDrg16KiBV1,
+            RegisteredSealProof::StackedDrg32KiBV1,
+            RegisteredSealProof::StackedDrg32MiBV1,
+            RegisteredSealProof::StackedDrg512MiBV1,
+            RegisteredSealProof::StackedDrg64GiBV1,
         ];
 
+        let mut porep_ids = std::collections::HashSet::new();
         for rsp in rsps {
             let _ = rsp.as_v1_config(); // make sure doesn't panic
             let _ = rsp.cache_params_path()?;
@@ -291,8 +661,22 @@ pub mod tests {
 
             assert!(rsp.partitions() > 0, "partitions() failed");
             assert!(u64::from(rsp.sector_size()) > 0, "sector_size() failed");
-            assert!(rsp.single_partition_proof_len() > 0, "single_partition_proof_len() failed");
-            assert_eq!(rsp.version(), Version::V1, "version() was wrong");
+            assert!(
+                porep_ids.insert(rsp.porep_id()),
+                "porep_id() was not unique"
+            );
+            assert!(
+                rsp.single_partition_proof_len() > 0,
+                "single_partition_proof_len() failed"
+            );
+            assert!(
+                matches!(rsp.version(), Version::V1 | Version::V1_1),
+                "version() was wrong"
+            );
+            assert!(
+                matches!(rsp.api_version(), ApiVersion::V1_0_0 | ApiVersion::V1_1_0),
+                "api_version() was wrong"
+            );
         }
 
         Ok(())
@@ -301,26 +685,77 @@ pub mod tests {
     #[test]
     fn test_registered_post_proof_accessors() -> Result<()> {
         let rpps = vec![
-            RegisteredPoStProof::StackedDrg1KiBV1,
-            RegisteredPoStProof::StackedDrg16MiBV1,
-            RegisteredPoStProof::StackedDrg256MiBV1,
-            RegisteredPoStProof::StackedDrg1GiBV1,
-            RegisteredPoStProof::StackedDrg32GiBV1
+            RegisteredPoStProof::StackedDrgWinning1KiBV1,
+            RegisteredPoStProof::StackedDrgWinning16MiBV1,
+            RegisteredPoStProof::StackedDrgWinning256MiBV1,
+            RegisteredPoStProof::StackedDrgWinning1GiBV1,
+            RegisteredPoStProof::StackedDrgWinning32GiBV1,
+            RegisteredPoStProof::StackedDrgWindow1KiBV1,
+            RegisteredPoStProof::StackedDrgWindow16MiBV1,
+            RegisteredPoStProof::StackedDrgWindow256MiBV1,
+            RegisteredPoStProof::StackedDrgWindow1GiBV1,
+            RegisteredPoStProof::StackedDrgWindow32GiBV1,
+            RegisteredPoStProof::StackedDrgWinning2KiBV1,
+            RegisteredPoStProof::StackedDrgWinning4KiBV1,
+            RegisteredPoStProof::StackedDrgWinning8MiBV1,
+            RegisteredPoStProof::StackedDrgWinning16KiBV1,
+            RegisteredPoStProof::StackedDrgWinning32KiBV1,
+            RegisteredPoStProof::StackedDrgWinning32MiBV1,
+            RegisteredPoStProof::StackedDrgWinning512MiBV1,
+            RegisteredPoStProof::StackedDrgWinning64GiBV1,
+            RegisteredPoStProof::StackedDrgWindow2KiBV1,
+            RegisteredPoStProof::StackedDrgWindow4KiBV1,
+            RegisteredPoStProof::StackedDrgWindow8MiBV1,
+            RegisteredPoStProof::StackedDrgWindow16KiBV1,
+            RegisteredPoStProof::StackedDrgWindow32KiBV1,
+            RegisteredPoStProof::StackedDrgWindow32MiBV1,
+            RegisteredPoStProof::StackedDrgWindow512MiBV1,
+            RegisteredPoStProof::StackedDrgWindow64GiBV1,
         ];
 
         for rpp in rpps {
             let _ = rpp.as_v1_config(); // make sure doesn't panic
-            assert!(rpp.cache_params_path().is_ok(), "cache_params_path() failed");
-            assert!(rpp.cache_verifying_key_path().is_ok(), "cache_verifying_key_path() failed");
-            assert!(rpp.circuit_identifier().is_ok(), "circuit_identifier() failed");
+            assert!(
+                rpp.cache_params_path().is_ok(),
+                "cache_params_path() failed"
+            );
+            assert!(
+                rpp.cache_verifying_key_path().is_ok(),
+                "cache_verifying_key_path() failed"
+            );
+            assert!(
+                rpp.circuit_identifier().is_ok(),
+                "circuit_identifier() failed"
+            );
             assert!(rpp.params_cid().is_ok(), "params_cid() failed");
             assert!(rpp.partitions() > 0, "partitions() failed");
             assert!(u64::from(rpp.sector_size()) > 0, "sector_size() failed");
-            assert!(rpp.single_partition_proof_len() > 0, "single_partition_proof_len() failed");
-            assert!(rpp.verifying_key_cid().is_ok(), "verifying_key_cid() failed");
+            assert!(
+                rpp.single_partition_proof_len() > 0,
+                "single_partition_proof_len() failed"
+            );
+            assert!(
+                rpp.verifying_key_cid().is_ok(),
+                "verifying_key_cid() failed"
+            );
             assert_eq!(rpp.version(), Version::V1, "version() was wrong");
+            assert_eq!(rpp.as_v1_config().typ, rpp.typ(), "typ() was wrong");
+            assert_eq!(
+                rpp.api_version(),
+                ApiVersion::V1_0_0,
+                "api_version() was wrong"
+            );
         }
 
         Ok(())
     }
+
+    #[test]
+    fn test_registered_aggregation_proof_accessors() {
+        assert_eq!(
+            RegisteredAggregationProof::SnarkPackV1.version(),
+            AggregateVersion::V1,
+            "version() was wrong"
+        );
+    }
 }